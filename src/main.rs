@@ -28,9 +28,14 @@ extern crate strum;
 #[macro_use] extern crate strum_macros;
 
 use console::style;
+use rand::seq::SliceRandom;
+use rand::Rng;
 use std::cmp::Ordering;
+use std::collections::HashMap;
 use std::collections::HashSet;
+use std::convert::TryFrom;
 use std::fmt;
+use std::str::FromStr;
 use strum::IntoEnumIterator;
 
 /*
@@ -219,6 +224,198 @@ impl Card {
             _ => 5
         }
     }
+    // The three "bouts" (oudlers): the Fool, the 1 of trumps (le Petit) and the
+    // 21 of trumps. Their count sets the target the taker must reach.
+    fn is_oudler(&self) -> bool {
+        matches!(
+            self,
+            Card(Figure::Fool)
+                | Card(Figure::Trump(Trump::One))
+                | Card(Figure::Trump(Trump::TwentyOne))
+        )
+    }
+}
+
+// The taker must reach a target depending on how many oudlers they won. Values
+// are on the ×10 scale the rest of the crate uses for card points.
+fn target_for_oudlers(oudlers: u8) -> u16 {
+    match oudlers {
+        0 => 560,
+        1 => 510,
+        2 => 410,
+        _ => 360,
+    }
+}
+
+// Outcome of scoring the taker's won cards against the oudler-dependent target.
+// Points, target and margin are all on the ×10 scale.
+struct ContractScore {
+    points: u16,
+    oudlers: u8,
+    target: u16,
+    made: bool,
+    margin: i32,
+}
+
+// Score the taker's won cards: tally them, count oudlers and sum points, then
+// compare against the target that the oudler count sets.
+fn score_contract(won: &[Card]) -> ContractScore {
+    // Tally each won card; a card must appear exactly once, otherwise both the
+    // point total and the oudler count would be wrong.
+    let mut counts: HashMap<&Card, u8> = HashMap::new();
+    for card in won {
+        *counts.entry(card).or_insert(0) += 1;
+    }
+    debug_assert!(
+        counts.values().all(|&n| n == 1),
+        "a card was counted more than once"
+    );
+
+    let mut points: u16 = 0;
+    let mut oudlers: u8 = 0;
+    for card in counts.keys() {
+        points += card.points() as u16;
+        if card.is_oudler() {
+            oudlers += 1;
+        }
+    }
+    let target = target_for_oudlers(oudlers);
+    let margin = points as i32 - target as i32;
+    ContractScore {
+        points,
+        oudlers,
+        target,
+        made: margin >= 0,
+        margin,
+    }
+}
+
+// The bids of the auction, in ascending precedence: each bid must strictly
+// exceed the standing one, so the declaration order is the ordering.
+#[derive(Clone, Copy, Eq, PartialEq, PartialOrd, Ord, Debug)]
+enum Contract {
+    Pass,
+    Petite,
+    Garde,
+    GardeSans,
+    GardeContre,
+}
+
+impl Contract {
+    // Factor the contract applies to the final score.
+    fn multiplier(&self) -> u16 {
+        match self {
+            Contract::Pass => 0,
+            Contract::Petite => 1,
+            Contract::Garde => 2,
+            Contract::GardeSans => 4,
+            Contract::GardeContre => 6,
+        }
+    }
+}
+
+// Who the chien counts for once play is over. Under Garde Contre the chien is
+// left to the defense; otherwise it ends up in the taker's tricks.
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+enum ChienOwner {
+    Taker,
+    Defense,
+}
+
+// The auction's outcome: the winning contract, the seat that took it, and where
+// the chien ends up.
+struct ResolvedContract {
+    contract: Contract,
+    taker: usize,
+    chien: ChienOwner,
+}
+
+impl ResolvedContract {
+    fn multiplier(&self) -> u16 {
+        self.contract.multiplier()
+    }
+
+    // Apply the contract multiplier to a scored hand's margin, the bridge from
+    // the auction into the contract-scoring subsystem.
+    fn apply(&self, score: &ContractScore) -> i32 {
+        i32::from(self.multiplier()) * score.margin
+    }
+}
+
+// Drives the auction: each seat bids in turn and only a bid strictly above the
+// standing contract is accepted, leaving the highest contract and its taker.
+struct Bidding {
+    highest: Contract,
+    taker: Option<usize>,
+}
+
+impl Bidding {
+    fn new() -> Self {
+        Self {
+            highest: Contract::Pass,
+            taker: None,
+        }
+    }
+
+    // Record `position`'s bid, accepting it only if it exceeds the standing
+    // contract. Returns whether the bid was taken.
+    fn bid(&mut self, position: usize, contract: Contract) -> bool {
+        if contract > self.highest {
+            self.highest = contract;
+            self.taker = Some(position);
+            true
+        } else {
+            false
+        }
+    }
+
+    // Resolve the auction, or `None` if everyone passed.
+    fn resolve(&self) -> Option<ResolvedContract> {
+        self.taker.map(|taker| ResolvedContract {
+            contract: self.highest,
+            taker,
+            chien: if self.highest == Contract::GardeContre {
+                ChienOwner::Defense
+            } else {
+                ChienOwner::Taker
+            },
+        })
+    }
+}
+
+impl Card {
+    // Does `self` still win the trick when `challenger` is played on top of it,
+    // the trick having been led in `led`? This is the contextual comparison the
+    // derived `PartialOrd` can't express: whether a card beats another depends
+    // on the led suit, not on any intrinsic order. `self` is the card currently
+    // winning the trick; we keep it unless `challenger` genuinely overtakes it.
+    fn beats(&self, challenger: &Card, led: Suit) -> bool {
+        // l'Excuse never wins, whoever plays it and whenever it is played.
+        if let Card(Figure::Fool) = challenger {
+            return true;
+        }
+        if let Card(Figure::Fool) = self {
+            return false;
+        }
+        match (&self.0, &challenger.0) {
+            (Figure::Trump(held), Figure::Trump(played)) => held >= played,
+            (Figure::Trump(_), Figure::Base(..)) => true,
+            (Figure::Base(..), Figure::Trump(_)) => false,
+            (Figure::Base(held_suit, held_rank), Figure::Base(played_suit, played_rank)) => {
+                // Only cards of the led suit are eligible; an off-suit discard
+                // can never overtake a card of the led suit.
+                if *played_suit != led {
+                    true
+                } else if *held_suit != led {
+                    false
+                } else {
+                    held_rank >= played_rank
+                }
+            }
+            // The Fool cases are handled above.
+            _ => true,
+        }
+    }
 }
 
 impl fmt::Display for Card {
@@ -227,45 +424,348 @@ impl fmt::Display for Card {
     }
 }
 
+// Typed failure when reading a card or hand from text, so callers get a clear
+// reason instead of a panic on malformed input.
+#[derive(Debug, PartialEq, Eq)]
+enum ParseCardError {
+    Empty,
+    UnknownSuit(String),
+    UnknownRank(String),
+    UnknownTrump(String),
+    Unrecognized(String),
+    Duplicate(String),
+}
+
+impl fmt::Display for ParseCardError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseCardError::Empty => write!(f, "empty card token"),
+            ParseCardError::UnknownSuit(s) => write!(f, "unknown suit: {}", s),
+            ParseCardError::UnknownRank(s) => write!(f, "unknown rank: {}", s),
+            ParseCardError::UnknownTrump(s) => write!(f, "unknown trump: {}", s),
+            ParseCardError::Unrecognized(s) => write!(f, "unrecognized card token: {}", s),
+            ParseCardError::Duplicate(s) => write!(f, "duplicate card: {}", s),
+        }
+    }
+}
+
+impl std::error::Error for ParseCardError {}
+
+impl TryFrom<&str> for Suit {
+    type Error = ParseCardError;
+    fn try_from(token: &str) -> Result<Self, Self::Error> {
+        match token {
+            "S" | "♠" => Ok(Suit::Spade),
+            "H" | "♥" => Ok(Suit::Heart),
+            "D" | "♦" => Ok(Suit::Diamond),
+            "C" | "♣" => Ok(Suit::Club),
+            other => Err(ParseCardError::UnknownSuit(other.to_string())),
+        }
+    }
+}
+
+impl FromStr for Suit {
+    type Err = ParseCardError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Suit::try_from(s)
+    }
+}
+
+impl TryFrom<&str> for Rank {
+    type Error = ParseCardError;
+    fn try_from(token: &str) -> Result<Self, Self::Error> {
+        // French letters (from the Display form) and their English equivalents
+        // are both accepted; pip values read as their number.
+        match token {
+            "1" | "A" => Ok(Rank::Ace),
+            "2" => Ok(Rank::Two),
+            "3" => Ok(Rank::Three),
+            "4" => Ok(Rank::Four),
+            "5" => Ok(Rank::Five),
+            "6" => Ok(Rank::Six),
+            "7" => Ok(Rank::Seven),
+            "8" => Ok(Rank::Eight),
+            "9" => Ok(Rank::Nine),
+            "10" => Ok(Rank::Ten),
+            "V" | "J" => Ok(Rank::Jack),
+            "C" | "N" => Ok(Rank::Knight),
+            "D" | "Q" => Ok(Rank::Queen),
+            "R" | "K" => Ok(Rank::King),
+            other => Err(ParseCardError::UnknownRank(other.to_string())),
+        }
+    }
+}
+
+impl FromStr for Rank {
+    type Err = ParseCardError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Rank::try_from(s)
+    }
+}
+
+impl TryFrom<&str> for Trump {
+    type Error = ParseCardError;
+    fn try_from(token: &str) -> Result<Self, Self::Error> {
+        match token.parse::<usize>() {
+            Ok(n) if (1..=21).contains(&n) => Ok(Trump::iter()
+                .nth(n - 1)
+                .expect("1..=21 is within the trump range")),
+            _ => Err(ParseCardError::UnknownTrump(token.to_string())),
+        }
+    }
+}
+
+impl FromStr for Trump {
+    type Err = ParseCardError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Trump::try_from(s)
+    }
+}
+
+impl Card {
+    // Compact, round-trippable text form: suit symbol + rank (French letters),
+    // "⸬N" for a trump and "*" for the Fool. `Card::try_from(card.token())`
+    // returns the same card.
+    fn token(&self) -> String {
+        match &self.0 {
+            Figure::Fool => "*".to_string(),
+            Figure::Trump(trump) => format!("⸬{}", (*trump as usize) + 1),
+            Figure::Base(suit, rank) => {
+                let rank = match rank {
+                    Rank::Jack => "V".to_string(),
+                    Rank::Knight => "C".to_string(),
+                    Rank::Queen => "D".to_string(),
+                    Rank::King => "R".to_string(),
+                    pip => ((*pip as usize) + 1).to_string(),
+                };
+                format!("{}{}", suit, rank)
+            }
+        }
+    }
+}
+
+impl TryFrom<&str> for Card {
+    type Error = ParseCardError;
+    fn try_from(token: &str) -> Result<Self, Self::Error> {
+        let token = token.trim();
+        if token.is_empty() {
+            return Err(ParseCardError::Empty);
+        }
+        if token == "*" || token == "★" {
+            return Ok(Card(Figure::Fool));
+        }
+        // ASCII form: "<prefix>:<value>", with "A" marking a trump.
+        if let Some((prefix, value)) = token.split_once(':') {
+            if prefix == "A" {
+                return Ok(Card(Figure::Trump(Trump::try_from(value)?)));
+            }
+            let suit = Suit::try_from(prefix)?;
+            return Ok(Card(Figure::Base(suit, Rank::try_from(value)?)));
+        }
+        // Unicode form: "⸬N" for a trump, otherwise a suit symbol then a rank.
+        if let Some(value) = token.strip_prefix('⸬') {
+            return Ok(Card(Figure::Trump(Trump::try_from(value)?)));
+        }
+        let mut chars = token.chars();
+        let head = chars.next().ok_or(ParseCardError::Empty)?;
+        let suit = Suit::try_from(head.to_string().as_str())?;
+        let rank = chars.as_str();
+        if rank.is_empty() {
+            return Err(ParseCardError::Unrecognized(token.to_string()));
+        }
+        Ok(Card(Figure::Base(suit, Rank::try_from(rank)?)))
+    }
+}
+
+impl FromStr for Card {
+    type Err = ParseCardError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Card::try_from(s)
+    }
+}
+
+// A trick is the cards played in one round, in play order, together with the
+// seat that led it. There is no total order over cards, so the winner is found
+// by walking the played cards and keeping the one that `beats` all challengers.
+struct Trick {
+    cards: Vec<Card>,
+    leader: usize,
+}
+
+impl Trick {
+    fn new(cards: Vec<Card>, leader: usize) -> Self {
+        Self { cards, leader }
+    }
+
+    // Index (in play order) of the card that takes the trick. The first
+    // non-Fool card sets the led suit; the Fool never wins.
+    fn winner(&self) -> usize {
+        // The led suit is that of the first non-Fool card. A trick led with a
+        // trump has no base led suit, but trumps beat every base card anyway so
+        // the placeholder is never consulted in that case.
+        let mut led = Suit::Spade;
+        let mut best = 0;
+        let mut found = false;
+        for (i, card) in self.cards.iter().enumerate() {
+            if let Card(Figure::Fool) = card {
+                continue;
+            }
+            if !found {
+                if let Card(Figure::Base(suit, _)) = card {
+                    led = *suit;
+                }
+                best = i;
+                found = true;
+                continue;
+            }
+            if !self.cards[best].beats(card, led) {
+                best = i;
+            }
+        }
+        best
+    }
+
+    // Seat that takes the trick, resolving the play-order winner against the
+    // leader's position around a `players`-seat table.
+    fn winning_seat(&self, players: usize) -> usize {
+        (self.leader + self.winner()) % players
+    }
+}
+
+// A player's seat around the table: a stable position and the hand they hold.
+struct Player {
+    position: usize,
+    hand: Vec<Card>,
+}
+
+impl Player {
+    fn new(position: usize) -> Self {
+        Self {
+            position,
+            hand: Vec::new(),
+        }
+    }
+}
+
+// A four-player table. Seating is settled by drawing for the deal before any
+// card is dealt.
+struct Table {
+    players: usize,
+}
+
+impl Table {
+    fn new(players: usize) -> Self {
+        Self { players }
+    }
+
+    // Each seat cuts a single card from a shuffled pack; the highest cut takes
+    // the deal and leads the first trick. Returns that seat's position.
+    fn draw_for_dealer(&self, rng: &mut impl Rng) -> usize {
+        let mut deck = Deck::new();
+        deck.shuffle(rng);
+        // The cut ranks trumps above base suits and leaves the Fool lowest,
+        // matching how a table settles ties when cutting for the deal.
+        let cut_value = |card: &Card| -> usize {
+            match card {
+                Card(Figure::Fool) => 0,
+                Card(Figure::Base(suit, rank)) => 1 + (*suit as usize) * 14 + (*rank as usize),
+                Card(Figure::Trump(trump)) => 100 + (*trump as usize),
+            }
+        };
+        let mut best = 0;
+        let mut best_value = 0;
+        for position in 0..self.players {
+            let value = cut_value(&deck.cards[position]);
+            if value > best_value {
+                best_value = value;
+                best = position;
+            }
+        }
+        best
+    }
+}
+
 struct Deck {
-    set: HashSet<Card>,
+    cards: Vec<Card>,
 }
 
 impl Deck {
     fn new() -> Self {
-        let mut deck = Self {
-            set: HashSet::new()
-        };
+        let mut deck = Self { cards: Vec::new() };
         for suit in Suit::iter() {
             for rank in Rank::iter() {
-                &deck.set.insert(Card(Figure::Base(suit, rank)));
+                deck.cards.push(Card(Figure::Base(suit, rank)));
             }
-            
         }
         for trump in Trump::iter() {
-            &deck.set.insert(Card(Figure::Trump(trump)));
+            deck.cards.push(Card(Figure::Trump(trump)));
         }
-        &deck.set.insert(Card(Figure::Fool));
+        deck.cards.push(Card(Figure::Fool));
         deck
     }
     fn points(&self) -> u16 {
         let mut res: u16 = 0;
-        for card in self.set.iter() {
+        for card in self.cards.iter() {
             res += card.points() as u16;
         }
         res
     }
+    // Shuffle the pack in place; dealing is only meaningful once the order is
+    // random.
+    fn shuffle(&mut self, rng: &mut impl Rng) {
+        self.cards.shuffle(rng);
+    }
+    // Deal the pack into `players` hands, setting aside the face-down talon (the
+    // "chien"): six cards for a four-player table. Cards are taken from the top
+    // in the current order, with the chien reserved one card at a time during
+    // the deal rather than off the top.
+    fn deal(&mut self, players: usize) -> (Vec<Player>, Vec<Card>) {
+        let chien_size = 6;
+        let mut hands: Vec<Player> = (0..players).map(Player::new).collect();
+        let mut chien: Vec<Card> = Vec::new();
+        let mut dealt = 0;
+        let mut seat = 0;
+        while let Some(card) = self.cards.pop() {
+            // Reserve the chien in the body of the deal, never from the very
+            // first cards, until it holds its full count.
+            if chien.len() < chien_size && dealt >= 3 && dealt % 3 == 0 {
+                chien.push(card);
+            } else {
+                hands[seat % players].hand.push(card);
+                seat += 1;
+            }
+            dealt += 1;
+        }
+        (hands, chien)
+    }
+}
+
+impl FromStr for Deck {
+    type Err = ParseCardError;
+    // Read a whitespace-separated list of card tokens into a deck (or hand),
+    // rejecting any card that appears twice.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut cards: Vec<Card> = Vec::new();
+        for token in s.split_whitespace() {
+            let card = Card::try_from(token)?;
+            if cards.contains(&card) {
+                return Err(ParseCardError::Duplicate(token.to_string()));
+            }
+            cards.push(card);
+        }
+        Ok(Deck { cards })
+    }
 }
 
 impl fmt::Display for Deck {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.set.iter().fold(String::new(), |mut acc, card| { acc.push_str(&card.to_string()); acc.push_str(" "); acc }))
+        write!(f, "{}", self.cards.iter().fold(String::new(), |mut acc, card| { acc.push_str(&card.to_string()); acc.push_str(" "); acc }))
     }
 }
 
 fn main() {
 
-    let mut deck = Deck::new();
+    let deck = Deck::new();
     println!("{}", deck);
 
 }
@@ -322,4 +822,173 @@ mod tests {
         cards.insert(Card(Figure::Base(Suit::Spade, Rank::Ace)));
         assert!(cards.len() == 3);
     }
+
+    #[test]
+    fn test_bidding_precedence() {
+        let mut bidding = Bidding::new();
+        assert!(bidding.bid(0, Contract::Petite));
+        // A bid that does not exceed the standing contract is refused.
+        assert!(!bidding.bid(1, Contract::Petite));
+        assert!(bidding.bid(2, Contract::Garde));
+        let resolved = bidding.resolve().unwrap();
+        assert_eq!(resolved.contract, Contract::Garde);
+        assert_eq!(resolved.taker, 2);
+        assert_eq!(resolved.multiplier(), 2);
+        assert_eq!(resolved.chien, ChienOwner::Taker);
+    }
+
+    #[test]
+    fn test_all_pass_has_no_taker() {
+        let bidding = Bidding::new();
+        assert!(bidding.resolve().is_none());
+    }
+
+    #[test]
+    fn test_garde_contre_gives_chien_to_defense() {
+        let mut bidding = Bidding::new();
+        bidding.bid(3, Contract::GardeContre);
+        let resolved = bidding.resolve().unwrap();
+        assert_eq!(resolved.chien, ChienOwner::Defense);
+        assert_eq!(resolved.multiplier(), 6);
+    }
+
+    #[test]
+    fn test_contract_multiplier_feeds_scoring() {
+        let mut bidding = Bidding::new();
+        bidding.bid(0, Contract::Garde);
+        let resolved = bidding.resolve().unwrap();
+        let won = vec![Card(Figure::Base(Suit::Heart, Rank::King))];
+        let score = score_contract(&won);
+        assert_eq!(resolved.apply(&score), 2 * score.margin);
+    }
+
+    #[test]
+    fn test_parse_both_forms() {
+        let king = Card(Figure::Base(Suit::Spade, Rank::King));
+        assert_eq!(Card::try_from("♠R").unwrap(), king);
+        assert_eq!(Card::try_from("S:K").unwrap(), king);
+        let trump = Card(Figure::Trump(Trump::TwentyOne));
+        assert_eq!(Card::try_from("⸬21").unwrap(), trump);
+        assert_eq!(Card::try_from("A:21").unwrap(), trump);
+        assert_eq!("*".parse::<Card>().unwrap(), Card(Figure::Fool));
+    }
+
+    #[test]
+    fn test_card_token_round_trips() {
+        for card in Deck::new().cards {
+            let token = card.token();
+            assert_eq!(Card::try_from(token.as_str()).unwrap(), card);
+        }
+    }
+
+    #[test]
+    fn test_parse_errors_are_typed() {
+        assert_eq!(Card::try_from("").unwrap_err(), ParseCardError::Empty);
+        assert_eq!(
+            Card::try_from("Z:5").unwrap_err(),
+            ParseCardError::UnknownSuit("Z".to_string())
+        );
+        assert_eq!(
+            Card::try_from("A:99").unwrap_err(),
+            ParseCardError::UnknownTrump("99".to_string())
+        );
+    }
+
+    #[test]
+    fn test_hand_rejects_duplicates() {
+        let hand = "♠R ⸬21 *".parse::<Deck>().unwrap();
+        assert_eq!(hand.cards.len(), 3);
+        assert!("♠R ♠R".parse::<Deck>().is_err());
+    }
+
+    #[test]
+    fn test_deal_distributes_whole_pack() {
+        let mut deck = Deck::new();
+        let (players, chien) = deck.deal(4);
+        assert_eq!(players.len(), 4);
+        assert_eq!(chien.len(), 6);
+        for player in &players {
+            assert_eq!(player.hand.len(), 18);
+        }
+        let total: usize = players.iter().map(|p| p.hand.len()).sum::<usize>() + chien.len();
+        assert_eq!(total, 78);
+    }
+
+    #[test]
+    fn test_draw_for_dealer_is_a_seat() {
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+        let mut rng = StdRng::seed_from_u64(42);
+        let table = Table::new(4);
+        let dealer = table.draw_for_dealer(&mut rng);
+        assert!(dealer < 4);
+    }
+
+    #[test]
+    fn test_score_counts_oudlers_and_target() {
+        let won = vec![
+            Card(Figure::Fool),
+            Card(Figure::Trump(Trump::One)),
+            Card(Figure::Trump(Trump::TwentyOne)),
+        ];
+        let score = score_contract(&won);
+        assert_eq!(score.oudlers, 3);
+        assert_eq!(score.target, 360);
+        // Three 4.5-point bouts (45 each on the ×10 scale), well short of the
+        // 36-point target (360).
+        assert_eq!(score.points, 135);
+        assert!(!score.made);
+        assert_eq!(score.margin, -225);
+    }
+
+    #[test]
+    fn test_score_zero_oudler_target() {
+        let won = vec![Card(Figure::Base(Suit::Heart, Rank::King))];
+        let score = score_contract(&won);
+        assert_eq!(score.oudlers, 0);
+        assert_eq!(score.target, 560);
+    }
+
+    #[test]
+    fn test_trick_trump_overtrumps_high_base() {
+        // Spade led with the King, then a lowly trump is thrown on: the trump
+        // takes it despite the King being the strongest base card.
+        let trick = Trick::new(
+            vec![
+                Card(Figure::Base(Suit::Spade, Rank::King)),
+                Card(Figure::Trump(Trump::Two)),
+            ],
+            0,
+        );
+        assert_eq!(trick.winner(), 1);
+    }
+
+    #[test]
+    fn test_trick_off_suit_discard_loses() {
+        // Spade led; a Heart King discarded off-suit can never win.
+        let trick = Trick::new(
+            vec![
+                Card(Figure::Base(Suit::Spade, Rank::Seven)),
+                Card(Figure::Base(Suit::Heart, Rank::King)),
+                Card(Figure::Base(Suit::Spade, Rank::Ten)),
+            ],
+            0,
+        );
+        assert_eq!(trick.winner(), 2);
+    }
+
+    #[test]
+    fn test_trick_fool_never_wins() {
+        // The Fool dropped into what looks like a winning slot still loses; here
+        // the highest trump takes the trick.
+        let trick = Trick::new(
+            vec![
+                Card(Figure::Base(Suit::Club, Rank::Ace)),
+                Card(Figure::Trump(Trump::Twenty)),
+                Card(Figure::Fool),
+            ],
+            0,
+        );
+        assert_eq!(trick.winner(), 1);
+    }
 }